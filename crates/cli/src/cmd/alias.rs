@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use libscoop::Session;
+
+use super::BUILTIN_COMMANDS;
+
+/// Maximum number of alias hops to follow before giving up. This guards
+/// against cycles like `alias.a = "b"` / `alias.b = "a"`.
+const MAX_ALIAS_DEPTH: usize = 8;
+
+/// Expand a user-defined alias at the front of `args`, splicing its
+/// token sequence in place of the alias name.
+///
+/// Aliases are read from the `[alias]` table of the session config, both
+/// as a single string (`alias.rm = "uninstall"`) and as a token array
+/// (`alias.up = ["update", "*"]`). Built-in commands always shadow
+/// aliases, so users cannot accidentally break core verbs. An alias may
+/// expand to another alias; such chains are followed for at most
+/// [`MAX_ALIAS_DEPTH`] hops, and expansion stops as soon as a name that
+/// was already expanded comes up again, so cycles cannot loop forever.
+pub fn expand_alias(session: &Session, args: Vec<String>) -> Vec<String> {
+    let config = session.config();
+    expand_alias_table(config.alias_table(), args)
+}
+
+/// The table-driven core of [`expand_alias`], split out so it can be
+/// unit tested without a live [`Session`].
+fn expand_alias_table(table: Option<&toml::value::Table>, mut args: Vec<String>) -> Vec<String> {
+    if args.is_empty() {
+        return args;
+    }
+
+    let Some(table) = table else {
+        return args;
+    };
+
+    let mut seen = HashSet::new();
+
+    for _ in 0..MAX_ALIAS_DEPTH {
+        let name = args[0].clone();
+
+        if BUILTIN_COMMANDS.contains(&name.as_str()) || !seen.insert(name.clone()) {
+            break;
+        }
+
+        match lookup(table, &name) {
+            Some(tokens) if !tokens.is_empty() => {
+                args.splice(0..1, tokens);
+            }
+            _ => break,
+        }
+    }
+
+    args
+}
+
+/// Resolve a single alias entry, supporting both the string and array
+/// forms of the `[alias]` table.
+fn lookup(table: &toml::value::Table, name: &str) -> Option<Vec<String>> {
+    match table.get(name)? {
+        toml::Value::String(s) => Some(s.split_whitespace().map(str::to_owned).collect()),
+        toml::Value::Array(arr) => Some(
+            arr.iter()
+                .filter_map(|v| v.as_str().map(str::to_owned))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand_alias_table;
+
+    fn table(pairs: &[(&str, toml::Value)]) -> toml::value::Table {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    fn args(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn expands_string_form() {
+        let table = table(&[("rm", toml::Value::String("uninstall".to_string()))]);
+        let result = expand_alias_table(Some(&table), args(&["rm", "git"]));
+        assert_eq!(result, args(&["uninstall", "git"]));
+    }
+
+    #[test]
+    fn expands_array_form() {
+        let table = table(&[(
+            "up",
+            toml::Value::Array(vec![
+                toml::Value::String("update".to_string()),
+                toml::Value::String("*".to_string()),
+            ]),
+        )]);
+        let result = expand_alias_table(Some(&table), args(&["up"]));
+        assert_eq!(result, args(&["update", "*"]));
+    }
+
+    #[test]
+    fn builtins_shadow_aliases() {
+        let table = table(&[("cache", toml::Value::String("cleanup".to_string()))]);
+        let result = expand_alias_table(Some(&table), args(&["cache", "rm", "*"]));
+        assert_eq!(result, args(&["cache", "rm", "*"]));
+    }
+
+    #[test]
+    fn cycles_terminate() {
+        let table = table(&[
+            ("a", toml::Value::String("b".to_string())),
+            ("b", toml::Value::String("a".to_string())),
+        ]);
+        // Must terminate rather than loop forever: `a` -> `b` -> `a` is
+        // caught as soon as `a` would be expanded a second time.
+        let result = expand_alias_table(Some(&table), args(&["a"]));
+        assert_eq!(result, args(&["a"]));
+    }
+
+    #[test]
+    fn no_alias_table_is_passthrough() {
+        let result = expand_alias_table(None, args(&["search", "git"]));
+        assert_eq!(result, args(&["search", "git"]));
+    }
+}
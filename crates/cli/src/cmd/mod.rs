@@ -1,3 +1,4 @@
+mod alias;
 mod bucket;
 mod cache;
 mod cleanup;
@@ -7,8 +8,10 @@ mod home;
 mod info;
 mod list;
 mod search;
+mod suggest;
 mod update;
 
+pub use alias::expand_alias;
 pub use bucket::cmd_bucket;
 pub use cache::cmd_cache;
 pub use cleanup::cmd_cleanup;
@@ -18,4 +21,28 @@ pub use home::cmd_home;
 pub use info::cmd_info;
 pub use list::cmd_list;
 pub use search::cmd_search;
-pub use update::cmd_update;
\ No newline at end of file
+pub use suggest::suggest_command;
+pub use update::cmd_update;
+
+/// Subcommand names built into hok. These always take precedence over
+/// any user-defined alias of the same name (see [`expand_alias`]).
+///
+/// This must be kept in sync with the clap `Command` definitions in the
+/// binary's arg-parsing setup: it includes `install`/`uninstall`, which
+/// (like `cat`) are wired up outside this crate's `cmd` module, so they
+/// don't show up among the `pub use cmd_*` re-exports above.
+pub(crate) const BUILTIN_COMMANDS: &[&str] = &[
+    "bucket",
+    "cache",
+    "cat",
+    "cleanup",
+    "config",
+    "hold",
+    "home",
+    "info",
+    "install",
+    "list",
+    "search",
+    "uninstall",
+    "update",
+];
\ No newline at end of file
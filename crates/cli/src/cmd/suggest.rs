@@ -0,0 +1,10 @@
+use libscoop::lev;
+
+use super::BUILTIN_COMMANDS;
+
+/// Suggest the built-in subcommand closest to an unrecognized `name`,
+/// for use in a "did you mean ...?" hint from the commands dispatcher.
+pub fn suggest_command(name: &str) -> Option<&'static str> {
+    let name = name.to_lowercase();
+    lev::closest(&name, BUILTIN_COMMANDS.iter().copied())
+}
@@ -0,0 +1,31 @@
+pub mod cmd;
+
+use libscoop::Session;
+
+/// Resolve a raw argument vector (as collected from `std::env::args()`,
+/// with the program name already stripped) against hok's built-in
+/// subcommands.
+///
+/// The user's `[alias]` config is expanded first via
+/// [`cmd::expand_alias`], so e.g. `alias.rm = "uninstall"` is in effect
+/// before the built-in set is consulted; built-ins always shadow
+/// aliases, so this can't be used to break a core verb. On success,
+/// returns the (possibly alias-expanded) arguments for the caller to
+/// hand off to clap for full parsing and dispatch to the matching
+/// `cmd_*` function. Returns `None`, having printed an error, if the
+/// resolved subcommand name isn't recognized.
+pub fn resolve_subcommand(session: &Session, args: Vec<String>) -> Option<Vec<String>> {
+    let args = cmd::expand_alias(session, args);
+
+    let name = args.first()?;
+
+    if cmd::BUILTIN_COMMANDS.contains(&name.as_str()) {
+        Some(args)
+    } else {
+        eprintln!("error: no such subcommand: '{}'", name);
+        if let Some(hint) = cmd::suggest_command(name) {
+            eprintln!("\nDid you mean '{}'?", hint);
+        }
+        None
+    }
+}
@@ -1,7 +1,16 @@
 use crate::error::Result;
+use md5::Md5;
 use once_cell::sync::Lazy;
+use rayon::prelude::*;
 use regex::{Regex, RegexBuilder};
-use std::{fs::DirEntry, path::PathBuf, result};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use std::{
+    fs::DirEntry,
+    io::Read,
+    path::{Path, PathBuf},
+    result,
+};
 
 /// A struct represents a downloaded cache item of scoop.
 #[derive(Debug)]
@@ -15,6 +24,128 @@ pub struct CacheEntry {
 #[derive(Debug)]
 pub struct CacheManager {
     working_dir: PathBuf,
+    pool: Option<rayon::ThreadPool>,
+}
+
+/// Hash algorithm declared by a package manifest's `hash` field, as an
+/// `algo:digest` prefix (bare digests are assumed sha256).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HashAlgorithm {
+    Sha256,
+    Sha1,
+    Sha512,
+    Md5,
+}
+
+impl HashAlgorithm {
+    fn parse(raw: &str) -> (HashAlgorithm, String) {
+        match raw.split_once(':') {
+            Some(("sha1", digest)) => (HashAlgorithm::Sha1, digest.to_string()),
+            Some(("sha512", digest)) => (HashAlgorithm::Sha512, digest.to_string()),
+            Some(("md5", digest)) => (HashAlgorithm::Md5, digest.to_string()),
+            Some(("sha256", digest)) => (HashAlgorithm::Sha256, digest.to_string()),
+            _ => (HashAlgorithm::Sha256, raw.to_string()),
+        }
+    }
+}
+
+/// Outcome of verifying a single [`CacheEntry`], returned from
+/// [`CacheManager::verify`].
+#[derive(Debug)]
+pub enum VerifyStatus {
+    Ok,
+    Mismatch { expected: String, actual: String },
+    MissingHash,
+}
+
+/// A single entry in the report produced by [`CacheManager::verify`].
+#[derive(Debug)]
+pub struct VerifyReport {
+    app_name: String,
+    file_name: String,
+    status: VerifyStatus,
+}
+
+impl VerifyReport {
+    pub fn app_name(&self) -> &str {
+        &self.app_name
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn status(&self) -> &VerifyStatus {
+        &self.status
+    }
+}
+
+const HASH_BUF_SIZE: usize = 64 * 1024;
+
+/// Stream `path` through the hasher for `algo` in fixed-size chunks, so
+/// large installers are never loaded into memory, and return the
+/// lowercase hex digest.
+fn hash_file(path: &Path, algo: HashAlgorithm) -> Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut buf = [0u8; HASH_BUF_SIZE];
+
+    macro_rules! digest_with {
+        ($hasher:expr) => {{
+            let mut hasher = $hasher;
+            loop {
+                let n = file.read(&mut buf)?;
+                if n == 0 {
+                    break;
+                }
+                hasher.update(&buf[..n]);
+            }
+            format!("{:x}", hasher.finalize())
+        }};
+    }
+
+    Ok(match algo {
+        HashAlgorithm::Sha256 => digest_with!(Sha256::new()),
+        HashAlgorithm::Sha1 => digest_with!(Sha1::new()),
+        HashAlgorithm::Sha512 => digest_with!(Sha512::new()),
+        HashAlgorithm::Md5 => digest_with!(Md5::new()),
+    })
+}
+
+/// Read a JSON value that's either a single string or an array of
+/// strings into a `Vec<String>`, the common shape of a manifest's
+/// `url`/`hash` fields.
+fn string_or_array(value: &serde_json::Value) -> Option<Vec<String>> {
+    match value {
+        serde_json::Value::String(s) => Some(vec![s.clone()]),
+        serde_json::Value::Array(arr) => {
+            Some(arr.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+        }
+        _ => None,
+    }
+}
+
+/// Read the hash declared for `file_name` out of a manifest file.
+///
+/// Manifests with a single download have scalar `url`/`hash` fields;
+/// manifests with multiple downloads (e.g. 32-bit/64-bit installers)
+/// have parallel arrays, where `hash[i]` is the hash of the file at
+/// `url[i]`. `file_name` is matched against each URL's basename so the
+/// right hash is picked for the cache entry actually being verified.
+fn expected_hash(manifest_path: &Path, file_name: &str) -> Option<(HashAlgorithm, String)> {
+    let content = std::fs::read_to_string(manifest_path).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+
+    let urls = string_or_array(value.get("url")?)?;
+    let hashes = string_or_array(value.get("hash")?)?;
+
+    let index = urls.iter().position(|url| {
+        url.rsplit('/')
+            .next()
+            .map(|basename| basename == file_name)
+            .unwrap_or(false)
+    })?;
+
+    Some(HashAlgorithm::parse(hashes.get(index)?))
 }
 
 impl CacheEntry {
@@ -46,8 +177,10 @@ impl CacheEntry {
         &self.file_name
     }
 
+    /// Size of the cache file in bytes, or `0` if it cannot be read
+    /// (e.g. it was removed from under us).
     pub fn size(&self) -> u64 {
-        self.entry.metadata().unwrap().len()
+        self.entry.metadata().map(|meta| meta.len()).unwrap_or(0)
     }
 
     pub fn version(&self) -> &str {
@@ -57,7 +190,49 @@ impl CacheEntry {
 
 impl CacheManager {
     pub fn new(working_dir: PathBuf) -> CacheManager {
-        CacheManager { working_dir }
+        CacheManager {
+            working_dir,
+            pool: None,
+        }
+    }
+
+    /// Create a [`CacheManager`], honoring `config`'s
+    /// `cache.thread_count` override (see [`set_thread_count`]) if one
+    /// is set.
+    ///
+    /// [`set_thread_count`]: CacheManager::set_thread_count
+    pub fn new_with_config(working_dir: PathBuf, config: &crate::config::Config) -> Result<CacheManager> {
+        let mut manager = CacheManager::new(working_dir);
+
+        if let Some(count) = config.cache_thread_count() {
+            manager.set_thread_count(count)?;
+        }
+
+        Ok(manager)
+    }
+
+    /// Scan and clean using a dedicated pool of `count` threads instead
+    /// of rayon's global pool (which defaults to one thread per logical
+    /// CPU). Useful on spinning disks, where unrestricted concurrency
+    /// can cause thrashing rather than a speedup.
+    pub fn set_thread_count(&mut self, count: usize) -> Result<()> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(count.max(1))
+            .build()
+            .map_err(std::io::Error::other)?;
+
+        self.pool = Some(pool);
+        Ok(())
+    }
+
+    /// Run `f`, a parallel-iterator pipeline, on the configured pool, or
+    /// on rayon's global pool if [`CacheManager::set_thread_count`] was
+    /// never called.
+    fn run<T: Send>(&self, f: impl FnOnce() -> T + Send) -> T {
+        match &self.pool {
+            Some(pool) => pool.install(f),
+            None => f(),
+        }
     }
 
     /// Collect all cache files represented as [`CacheEntry`]
@@ -68,14 +243,20 @@ impl CacheManager {
                 .unwrap()
         });
 
-        let entries = self
+        let dir_entries: Vec<DirEntry> = self
             .working_dir
             .read_dir()?
             .filter_map(result::Result::ok)
             .filter(|de| RE.is_match(de.file_name().to_str().unwrap()))
-            .map(|entry| CacheEntry::new(entry))
             .collect();
 
+        let entries = self.run(|| {
+            dir_entries
+                .into_par_iter()
+                .map(CacheEntry::new)
+                .collect()
+        });
+
         Ok(entries)
     }
 
@@ -101,8 +282,25 @@ impl CacheManager {
     }
 
     /// Remove all Scoop cache files
+    ///
+    /// Unlike [`CacheManager::get_all`], this sweeps every entry in the
+    /// cache directory rather than just ones matching the
+    /// `app#version#file` naming convention, so stray/partial files left
+    /// behind by an interrupted download are also purged.
     pub fn clean_all(&self) -> Result<()> {
-        Ok(crate::fs::empty_dir(&self.working_dir)?)
+        let dir_entries: Vec<DirEntry> = self
+            .working_dir
+            .read_dir()?
+            .filter_map(result::Result::ok)
+            .collect();
+
+        self.run(|| {
+            dir_entries
+                .into_par_iter()
+                .try_for_each(|entry| std::fs::remove_file(entry.path()))
+        })?;
+
+        Ok(())
     }
 
     /// Remove `app_name` related cache files, `*` wildcard pattern is support.
@@ -111,15 +309,77 @@ impl CacheManager {
             "*" => self.clean_all()?,
             _ => {
                 let cache_items = self.get(app_name.as_ref())?;
-                for item in cache_items {
-                    std::fs::remove_file(item.entry.path())?;
+                self.run(|| {
+                    cache_items
+                        .into_par_iter()
+                        .try_for_each(|item| std::fs::remove_file(item.entry.path()))
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verify cached files for `app_name` (the same `*` wildcard
+    /// supported by [`CacheManager::get`]) against the hash declared in
+    /// the manifest they were downloaded with.
+    pub fn verify(&self, app_name: &str) -> Result<Vec<VerifyReport>> {
+        let entries = self.get(app_name)?;
+
+        let reports = entries
+            .into_iter()
+            .map(|entry| {
+                let status = self.verify_entry(&entry);
+                VerifyReport {
+                    app_name: entry.app_name().to_string(),
+                    file_name: entry.file_name().to_string(),
+                    status,
                 }
+            })
+            .collect();
+
+        Ok(reports)
+    }
+
+    /// Remove cache entries whose hash actively mismatches the one
+    /// declared by their manifest, leaving intact entries and entries
+    /// with no hash to check against ([`VerifyStatus::MissingHash`])
+    /// untouched — a missing hash means "unverifiable", not "corrupt".
+    pub fn clean_corrupt(&self) -> Result<()> {
+        let entries = self.get_all()?;
+
+        for entry in entries {
+            if matches!(self.verify_entry(&entry), VerifyStatus::Mismatch { .. }) {
+                std::fs::remove_file(entry.entry.path())?;
             }
         }
 
         Ok(())
     }
 
+    fn verify_entry(&self, entry: &CacheEntry) -> VerifyStatus {
+        let manifest = self.manifest_path(entry.app_name(), entry.version());
+        let hash = manifest.and_then(|path| expected_hash(&path, entry.file_name()));
+
+        match hash {
+            None => VerifyStatus::MissingHash,
+            Some((algo, expected)) => match hash_file(&entry.entry.path(), algo) {
+                Ok(actual) if actual.eq_ignore_ascii_case(&expected) => VerifyStatus::Ok,
+                Ok(actual) => VerifyStatus::Mismatch { expected, actual },
+                Err(_) => VerifyStatus::MissingHash,
+            },
+        }
+    }
+
+    /// Locate the manifest an installed app/version was downloaded
+    /// with, assuming the conventional `<scoop_root>/apps` layout next
+    /// to the cache directory.
+    fn manifest_path(&self, app_name: &str, version: &str) -> Option<PathBuf> {
+        let apps_root = self.working_dir.parent()?.join("apps");
+        let path = apps_root.join(app_name).join(version).join("manifest.json");
+        path.is_file().then_some(path)
+    }
+
     pub fn create<S: AsRef<str>>(&self, filename: S) -> PathBuf {
         let path = self.working_dir.join(filename.as_ref());
         let mut tmp_path = path.clone().into_os_string();
@@ -137,3 +397,88 @@ impl CacheManager {
         path
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_prefixed_and_bare_digests() {
+        assert_eq!(
+            HashAlgorithm::parse("sha1:abc123"),
+            (HashAlgorithm::Sha1, "abc123".to_string())
+        );
+        assert_eq!(
+            HashAlgorithm::parse("sha512:def456"),
+            (HashAlgorithm::Sha512, "def456".to_string())
+        );
+        assert_eq!(
+            HashAlgorithm::parse("md5:ghi789"),
+            (HashAlgorithm::Md5, "ghi789".to_string())
+        );
+        assert_eq!(
+            HashAlgorithm::parse("sha256:jkl012"),
+            (HashAlgorithm::Sha256, "jkl012".to_string())
+        );
+        assert_eq!(
+            HashAlgorithm::parse("bare-digest"),
+            (HashAlgorithm::Sha256, "bare-digest".to_string())
+        );
+    }
+
+    /// Write `content` to a fresh temp manifest file and return its path.
+    fn write_manifest(label: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("hok-expected-hash-test-{}.json", label));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn expected_hash_reads_scalar_url_and_hash() {
+        let path = write_manifest(
+            "scalar",
+            r#"{"url": "https://example.com/app.exe", "hash": "sha1:abc123"}"#,
+        );
+
+        assert_eq!(
+            expected_hash(&path, "app.exe"),
+            Some((HashAlgorithm::Sha1, "abc123".to_string()))
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn expected_hash_indexes_array_by_matching_file_name() {
+        let path = write_manifest(
+            "array",
+            r#"{
+                "url": ["https://example.com/app32.exe", "https://example.com/app64.exe"],
+                "hash": ["sha1:thirty-two", "sha1:sixty-four"]
+            }"#,
+        );
+
+        assert_eq!(
+            expected_hash(&path, "app64.exe"),
+            Some((HashAlgorithm::Sha1, "sixty-four".to_string()))
+        );
+        assert_eq!(
+            expected_hash(&path, "app32.exe"),
+            Some((HashAlgorithm::Sha1, "thirty-two".to_string()))
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn expected_hash_returns_none_for_unmatched_file_name() {
+        let path = write_manifest(
+            "unmatched",
+            r#"{"url": "https://example.com/app.exe", "hash": "sha1:abc123"}"#,
+        );
+
+        assert_eq!(expected_hash(&path, "other.exe"), None);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use crate::error::Result;
+
+/// Parsed contents of hok's `config.toml`.
+#[derive(Debug, Clone)]
+pub struct Config {
+    cat_style: String,
+    alias: Option<toml::value::Table>,
+    cache_thread_count: Option<usize>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            cat_style: "auto".to_string(),
+            alias: None,
+            cache_thread_count: None,
+        }
+    }
+}
+
+impl Config {
+    /// Load configuration from `path`, falling back to defaults when
+    /// the file does not exist.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Config> {
+        let path = path.as_ref();
+        if !path.is_file() {
+            return Ok(Config::default());
+        }
+
+        let content = std::fs::read_to_string(path)?;
+        let value: toml::Value = content.parse().map_err(std::io::Error::other)?;
+
+        let cat_style = value
+            .get("cat_style")
+            .and_then(|v| v.as_str())
+            .unwrap_or("auto")
+            .to_string();
+
+        let alias = value.get("alias").and_then(|v| v.as_table()).cloned();
+
+        let cache_thread_count = value
+            .get("cache")
+            .and_then(|v| v.get("thread_count"))
+            .and_then(|v| v.as_integer())
+            .map(|n| n as usize);
+
+        Ok(Config {
+            cat_style,
+            alias,
+            cache_thread_count,
+        })
+    }
+
+    /// `bat`'s `--style` argument used by `cmd_cat`.
+    pub fn cat_style(&self) -> &str {
+        &self.cat_style
+    }
+
+    /// The `[alias]` table, if the config defines any user aliases.
+    pub fn alias_table(&self) -> Option<&toml::value::Table> {
+        self.alias.as_ref()
+    }
+
+    /// `cache.thread_count`, the number of threads `CacheManager` should
+    /// use for scanning/cleaning, if the user has overridden it.
+    pub fn cache_thread_count(&self) -> Option<usize> {
+        self.cache_thread_count
+    }
+}
@@ -0,0 +1,74 @@
+//! Levenshtein (edit) distance, shared by the "did you mean ...?" hints
+//! in the CLI crate's command/package lookups.
+
+/// Compute the Levenshtein (edit) distance between `a` and `b`.
+pub fn distance(a: &str, b: &str) -> usize {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+
+    if a.is_empty() {
+        return b.len();
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, &ac) in a.iter().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, &bc) in b.iter().enumerate() {
+            let cost = (ac != bc) as usize;
+            let cur = std::cmp::min(std::cmp::min(row[j] + 1, row[j + 1] + 1), prev + cost);
+            prev = row[j + 1];
+            row[j + 1] = cur;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Find the closest match to `query` among `candidates`, within an edit
+/// distance of roughly a third of the query's length.
+pub fn closest<'a>(query: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let threshold = std::cmp::max(1, query.len() / 3);
+
+    candidates
+        .map(|candidate| (candidate, distance(query, candidate)))
+        .filter(|(_, d)| *d <= threshold)
+        .min_by_key(|(_, d)| *d)
+        .map(|(candidate, _)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_handles_empty_strings() {
+        assert_eq!(distance("", ""), 0);
+        assert_eq!(distance("abc", ""), 3);
+        assert_eq!(distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn distance_counts_edits() {
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("bucket", "bucket"), 0);
+        assert_eq!(distance("cach", "cache"), 1);
+    }
+
+    #[test]
+    fn closest_picks_nearest_within_threshold() {
+        let candidates = ["bucket", "cache", "cleanup", "config"];
+        assert_eq!(closest("cach", candidates.into_iter()), Some("cache"));
+    }
+
+    #[test]
+    fn closest_rejects_far_matches() {
+        let candidates = ["bucket", "cache", "cleanup", "config"];
+        assert_eq!(closest("xyz", candidates.into_iter()), None);
+    }
+}
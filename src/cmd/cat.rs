@@ -1,6 +1,9 @@
 use clap::ArgMatches;
-use libscoop::{operation, QueryOption, Session};
-use std::{path::Path, process::Command};
+use libscoop::{lev, operation, QueryOption, Session};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 use crate::Result;
 
@@ -11,28 +14,40 @@ pub fn cmd_cat(matches: &ArgMatches, session: &Session) -> Result<()> {
         let result = operation::package_query(session, queries, options, false)?;
 
         match result.len() {
-            0 => eprintln!("Could not find package named '{}'.", query),
+            0 => {
+                eprintln!("Could not find package named '{}'.", query);
+                let all = operation::package_query(session, vec![], vec![], false)?;
+                let query = query.to_lowercase();
+                let names = all.iter().map(|pkg| pkg.name());
+                if let Some(hint) = lev::closest(&query, names) {
+                    eprintln!("Did you mean '{}'?", hint);
+                }
+            }
             1 => {
                 let package = &result[0];
-                let cat = match is_program_available("bat.exe") {
-                    true => "bat.exe",
-                    false => "type",
-                };
-                let config = session.config();
-                let cat_args = match cat == "bat.exe" {
-                    false => vec![],
-                    true => {
+                let bat = find_executable("bat");
+
+                let mut command = Command::new("cmd");
+                command.arg("/C");
+
+                match &bat {
+                    Some(path) => {
+                        let config = session.config();
                         let cat_style = config.cat_style();
-                        vec!["--no-paging", "--style", cat_style, "--language", "json"]
+                        command.arg(path).arg(package.manfest_path()).args([
+                            "--no-paging",
+                            "--style",
+                            cat_style,
+                            "--language",
+                            "json",
+                        ]);
+                    }
+                    None => {
+                        command.arg("type").arg(package.manfest_path());
                     }
-                };
-
-                let mut child = Command::new("cmd")
-                    .arg("/C")
-                    .arg(cat)
-                    .arg(package.manfest_path())
-                    .args(cat_args)
-                    .spawn()?;
+                }
+
+                let mut child = command.spawn()?;
                 child.wait()?;
             }
             _ => {
@@ -53,15 +68,110 @@ pub fn cmd_cat(matches: &ArgMatches, session: &Session) -> Result<()> {
     Ok(())
 }
 
-/// Check if a given executable is available on the system
-fn is_program_available(exe: &str) -> bool {
-    if let Ok(path) = std::env::var("PATH") {
-        for p in path.split(';') {
-            let path = Path::new(p).join(exe);
-            if std::fs::metadata(path).is_ok() {
-                return true;
+/// Resolve `name` to a full path by searching `PATH`, the same way a
+/// shell would.
+///
+/// On Windows, a bare name without an extension is tried against each
+/// extension in `PATHEXT` (defaulting to `.COM;.EXE;.BAT;.CMD`) so that
+/// e.g. `bat` resolves to `bat.exe` without the caller having to know
+/// the extension up front. `PATH` entries are parsed with
+/// [`std::env::split_paths`], which already understands the quoted
+/// segments `PATH` can contain on Windows. Returns the first candidate
+/// that is a regular file, so callers can invoke it directly.
+pub(crate) fn find_executable<S: AsRef<str>>(name: S) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    let pathext = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string());
+    resolve(name.as_ref(), &path_var, &pathext)
+}
+
+/// The search-path-driven core of [`find_executable`], split out so it
+/// can be unit tested against a synthetic `PATH`/`PATHEXT` instead of
+/// the real process environment.
+fn resolve(name: &str, path_var: &std::ffi::OsStr, pathext: &str) -> Option<PathBuf> {
+    let has_extension = Path::new(name).extension().is_some();
+    let extensions: Vec<&str> = pathext.split(';').filter(|ext| !ext.is_empty()).collect();
+
+    for dir in std::env::split_paths(path_var) {
+        if dir.as_os_str().is_empty() {
+            continue;
+        }
+
+        let candidate = dir.join(name);
+        if is_regular_file(&candidate) {
+            return Some(candidate);
+        }
+
+        if !has_extension {
+            for ext in &extensions {
+                let candidate = dir.join(format!("{}{}", name, ext));
+                if is_regular_file(&candidate) {
+                    return Some(candidate);
+                }
             }
         }
     }
-    false
+
+    None
+}
+
+fn is_regular_file(path: &Path) -> bool {
+    path.metadata().map(|meta| meta.is_file()).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve;
+    use std::{env, fs};
+
+    /// A temp directory cleaned up on drop, for test isolation.
+    struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        fn new(label: &str) -> TempDir {
+            let dir = env::temp_dir().join(format!("hok-find-executable-test-{}", label));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn resolves_verbatim_name() {
+        let dir = TempDir::new("verbatim");
+        fs::write(dir.0.join("bat"), b"").unwrap();
+
+        let found = resolve("bat", dir.0.as_os_str(), ".COM;.EXE;.BAT;.CMD");
+        assert_eq!(found, Some(dir.0.join("bat")));
+    }
+
+    #[test]
+    fn resolves_via_pathext_when_bare_name_is_missing() {
+        let dir = TempDir::new("pathext");
+        fs::write(dir.0.join("bat.EXE"), b"").unwrap();
+
+        let found = resolve("bat", dir.0.as_os_str(), ".COM;.EXE;.BAT;.CMD");
+        assert_eq!(found, Some(dir.0.join("bat.EXE")));
+    }
+
+    #[test]
+    fn does_not_append_pathext_when_name_already_has_an_extension() {
+        let dir = TempDir::new("has-extension");
+        fs::write(dir.0.join("bat.EXE.COM"), b"").unwrap();
+
+        let found = resolve("bat.EXE", dir.0.as_os_str(), ".COM;.EXE;.BAT;.CMD");
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn returns_none_when_nothing_matches() {
+        let dir = TempDir::new("missing");
+        let found = resolve("bat", dir.0.as_os_str(), ".COM;.EXE;.BAT;.CMD");
+        assert_eq!(found, None);
+    }
 }